@@ -26,6 +26,386 @@ pub trait Format {
     ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>>;
 }
 
+/// The inverse of [`Format::parse`]: renders a [`Value`] back out as format
+/// specific text.
+///
+/// This is a separate trait rather than a second method on [`Format`] so that
+/// read-only formats (or ones with no sensible textual rendering) aren't
+/// forced to implement it. Pairing it with `Format` enables round-tripping
+/// (load, mutate the value tree, write back), dumping the merged effective
+/// configuration for debugging, and writing out defaults files.
+pub trait FormatEmit {
+    /// Renders `value` as this format's text representation.
+    ///
+    /// Implementations walk the value tree the same way [`from_parsed_value`]
+    /// builds it, just in reverse. How [`ValueKind::Nil`] is rendered, and
+    /// whether key ordering in a [`ValueKind::Table`] is preserved, are left
+    /// up to each format to document.
+    fn emit(&self, value: &Value) -> Result<String, Box<dyn Error + Send + Sync>>;
+}
+
+/// A serializable mirror of a [`Value`], used by [`FormatEmit`] implementations
+/// to render a value tree back out as format-specific text via `serde`
+/// rather than each format hand-rolling its own walk.
+///
+/// `Nil` serializes as whatever "no value" representation a format natively
+/// supports (JSON/YAML `null`; TOML has no such concept, so round-tripping a
+/// `Nil` through the TOML format is a known limitation, not specially
+/// handled here). `DateTime` always serializes as its canonical string form;
+/// emitting native TOML datetime syntax back out is a possible follow-up.
+/// Table keys keep the order [`Map`] stores them in, except in TOML output,
+/// where each table's scalar entries are moved ahead of its sub-tables (see
+/// `TomlEmitValue`).
+#[cfg(any(feature = "json", feature = "yaml", feature = "toml"))]
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+enum EmitValue {
+    Nil,
+    Boolean(bool),
+    I64(i64),
+    I128(i128),
+    U64(u64),
+    U128(u128),
+    Float(f64),
+    #[cfg(feature = "toml")]
+    DateTime(String),
+    String(String),
+    Array(Vec<EmitValue>),
+    Table(Map<String, EmitValue>),
+}
+
+// The inverse of from_parsed_value's walk, shared by the built-in formats'
+// FormatEmit implementations.
+#[cfg(any(feature = "json", feature = "yaml", feature = "toml"))]
+fn to_emit_value(value: &Value) -> EmitValue {
+    match &value.kind {
+        ValueKind::Nil => EmitValue::Nil,
+        ValueKind::Boolean(v) => EmitValue::Boolean(*v),
+        ValueKind::I64(v) => EmitValue::I64(*v),
+        ValueKind::I128(v) => EmitValue::I128(*v),
+        ValueKind::U64(v) => EmitValue::U64(*v),
+        ValueKind::U128(v) => EmitValue::U128(*v),
+        ValueKind::Float(v) => EmitValue::Float(*v),
+        #[cfg(feature = "toml")]
+        ValueKind::DateTime(v) => EmitValue::DateTime(v.text.clone()),
+        ValueKind::String(v) => EmitValue::String(v.clone()),
+
+        ValueKind::Array(items) => EmitValue::Array(items.iter().map(to_emit_value).collect()),
+
+        ValueKind::Table(table) => EmitValue::Table(
+            table
+                .iter()
+                .map(|(k, v)| (k.clone(), to_emit_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// The JSON configuration format.
+#[cfg(feature = "json")]
+#[derive(Clone, Debug, Default)]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl Format for Json {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
+        let value = from_parsed_value(uri, serde_json::from_str(text)?);
+        extract_root_table(uri, value)
+    }
+}
+
+#[cfg(feature = "json")]
+impl FormatEmit for Json {
+    fn emit(&self, value: &Value) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(serde_json::to_string_pretty(&to_emit_value(value))?)
+    }
+}
+
+/// The YAML configuration format.
+#[cfg(feature = "yaml")]
+#[derive(Clone, Debug, Default)]
+pub struct Yaml;
+
+#[cfg(feature = "yaml")]
+impl Format for Yaml {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
+        let value = from_parsed_value(uri, serde_yaml::from_str(text)?);
+        extract_root_table(uri, value)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl FormatEmit for Yaml {
+    fn emit(&self, value: &Value) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(serde_yaml::to_string(&to_emit_value(value))?)
+    }
+}
+
+/// The TOML configuration format.
+#[cfg(feature = "toml")]
+#[derive(Clone, Debug, Default)]
+pub struct Toml;
+
+#[cfg(feature = "toml")]
+impl Format for Toml {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
+        let value = from_parsed_value(uri, toml::from_str(text)?);
+        extract_root_table(uri, value)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl FormatEmit for Toml {
+    fn emit(&self, value: &Value) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let emit_value = to_emit_value(value);
+
+        Ok(toml::to_string(&TomlEmitValue(&emit_value))?)
+    }
+}
+
+// Wraps an `EmitValue` for TOML serialization specifically: the `toml` crate
+// requires every non-table value in a map to be emitted before any nested
+// table, so a table whose keys happen to interleave scalars and sub-tables
+// (e.g. from a merged effective-config dump) would otherwise fail to
+// serialize with "values must be emitted before tables". This reorders each
+// table's entries (scalars and arrays-of-scalars first, tables and
+// arrays-of-tables last) right before handing them to `serde`, stably, so
+// relative order within each group still matches `Map`'s own iteration order.
+#[cfg(feature = "toml")]
+struct TomlEmitValue<'v>(&'v EmitValue);
+
+#[cfg(feature = "toml")]
+impl serde::Serialize for TomlEmitValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::{SerializeMap, SerializeSeq};
+
+        match self.0 {
+            EmitValue::Nil => serializer.serialize_unit(),
+            EmitValue::Boolean(v) => serializer.serialize_bool(*v),
+            EmitValue::I64(v) => serializer.serialize_i64(*v),
+            EmitValue::I128(v) => serializer.serialize_i128(*v),
+            EmitValue::U64(v) => serializer.serialize_u64(*v),
+            EmitValue::U128(v) => serializer.serialize_u128(*v),
+            EmitValue::Float(v) => serializer.serialize_f64(*v),
+            EmitValue::DateTime(v) => serializer.serialize_str(v),
+            EmitValue::String(v) => serializer.serialize_str(v),
+
+            EmitValue::Array(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&TomlEmitValue(item))?;
+                }
+                seq.end()
+            }
+
+            EmitValue::Table(table) => {
+                let mut entries: Vec<_> = table.iter().collect();
+                entries.sort_by_key(|(_, v)| toml_emits_after_scalars(v));
+
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (k, v) in entries {
+                    map.serialize_entry(k, &TomlEmitValue(v))?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+fn toml_emits_after_scalars(value: &EmitValue) -> bool {
+    match value {
+        EmitValue::Table(_) => true,
+        EmitValue::Array(items) => matches!(items.first(), Some(EmitValue::Table(_))),
+        _ => false,
+    }
+}
+
+/// The [Dhall](https://dhall-lang.org) configuration format.
+///
+/// Dhall is a typed, programmable configuration language. Parsing normalizes the
+/// expression to resolve all typed computation (records, unions, functions, `let`
+/// bindings, …) before lowering the result directly into a [`Value`] tree of the
+/// same shape the other formats produce: records become [`ValueKind::Table`],
+/// lists become [`ValueKind::Array`], optionals become their inner value (or
+/// [`ValueKind::Nil`] when empty), and naturals/integers/doubles/text/bools map
+/// onto the matching scalar variants.
+///
+/// By default, imports (`./other.dhall`, remote `https://…` imports, environment
+/// variable imports, …) are rejected so that parsing a configuration file can't
+/// pull in arbitrary external content unless the caller explicitly opts in via
+/// [`Dhall::with_imports`].
+#[cfg(feature = "dhall")]
+#[derive(Clone, Debug, Default)]
+pub struct Dhall {
+    allow_imports: bool,
+}
+
+#[cfg(feature = "dhall")]
+impl Dhall {
+    /// Creates a new Dhall format that rejects unresolved imports.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows the parsed expression to resolve imports while normalizing.
+    pub fn with_imports(mut self, allow_imports: bool) -> Self {
+        self.allow_imports = allow_imports;
+        self
+    }
+}
+
+#[cfg(feature = "dhall")]
+impl Format for Dhall {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
+        let simple_value = serde_dhall::from_str(text)
+            .imports(self.allow_imports)
+            .parse::<serde_dhall::SimpleValue>()?;
+
+        let value = from_dhall_value(uri, &simple_value);
+
+        extract_root_table(uri, value)
+    }
+}
+
+// Structural walk of a normalized Dhall expression into a Value, mirroring
+// from_parsed_value above. Unlike the other formats this doesn't round-trip
+// through ParsedValue/serde, since serde_dhall already hands us a fully
+// normalized, typed tree with no ambiguity left to resolve.
+#[cfg(feature = "dhall")]
+fn from_dhall_value(uri: Option<&String>, value: &serde_dhall::SimpleValue) -> Value {
+    use serde_dhall::{NumKind, SimpleValue};
+
+    let vk = match value {
+        SimpleValue::Num(NumKind::Bool(v)) => ValueKind::Boolean(*v),
+        SimpleValue::Num(NumKind::Natural(v)) => ValueKind::U64(*v),
+        SimpleValue::Num(NumKind::Integer(v)) => ValueKind::I64(*v),
+        SimpleValue::Num(NumKind::Double(v)) => ValueKind::Float((*v).into()),
+        SimpleValue::Text(v) => ValueKind::String(v.clone()),
+
+        SimpleValue::Optional(v) => match v {
+            Some(inner) => from_dhall_value(uri, inner).kind,
+            None => ValueKind::Nil,
+        },
+
+        SimpleValue::List(items) => {
+            let array = items.iter().map(|item| from_dhall_value(uri, item)).collect();
+
+            ValueKind::Array(array)
+        }
+
+        SimpleValue::Record(fields) => {
+            let table = fields
+                .iter()
+                .map(|(k, v)| (k.clone(), from_dhall_value(uri, v)))
+                .collect();
+
+            ValueKind::Table(table)
+        }
+
+        // A bare union alternative (no payload) lowers to its tag name, same as
+        // the string a user would otherwise write for an externally tagged enum.
+        SimpleValue::Union(tag, None) => ValueKind::String(tag.clone()),
+
+        // A union alternative carrying a value lowers to a single-key table,
+        // `{ <tag> = <value> }`, matching the externally tagged shape the rest
+        // of the crate already expects for enum variants with data.
+        SimpleValue::Union(tag, Some(inner)) => {
+            let mut table = Map::new();
+            table.insert(tag.clone(), from_dhall_value(uri, inner));
+            ValueKind::Table(table)
+        }
+    };
+
+    Value::new(uri, vk)
+}
+
+#[cfg(feature = "dhall")]
+impl FormatEmit for Dhall {
+    fn emit(&self, value: &Value) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(emit_dhall_value(value))
+    }
+}
+
+// Dhall's Double literal grammar requires either a decimal point or an
+// exponent. Rust's `Display` for f64 omits both for an integral value
+// (`1.0` -> "1"), which Dhall would then parse back as a Natural rather than
+// a Double, silently changing the value's type on round-trip.
+#[cfg(feature = "dhall")]
+fn format_dhall_double(v: f64) -> String {
+    let text = format!("{v:?}");
+
+    if text.contains(['.', 'e', 'E']) {
+        text
+    } else {
+        format!("{text}.0")
+    }
+}
+
+// Renders a Value as a Dhall expression. Table keys keep the order they're
+// stored in (Map's own iteration order), and Nil - which Dhall has no direct
+// equivalent for - renders as the empty record `{=}`, the same "no data"
+// literal Dhall itself uses for the unit type.
+#[cfg(feature = "dhall")]
+fn emit_dhall_value(value: &Value) -> String {
+    match &value.kind {
+        ValueKind::Nil => "{=}".to_string(),
+        ValueKind::Boolean(v) => v.to_string(),
+        ValueKind::I64(v) => format!("{v:+}"),
+        ValueKind::I128(v) => format!("{v:+}"),
+        ValueKind::U64(v) => v.to_string(),
+        ValueKind::U128(v) => v.to_string(),
+        ValueKind::Float(v) => format_dhall_double(*v),
+        ValueKind::String(v) => format!("{v:?}"),
+        #[cfg(feature = "toml")]
+        ValueKind::DateTime(v) => format!("{:?}", v.text),
+
+        ValueKind::Array(items) => {
+            let items = items
+                .iter()
+                .map(emit_dhall_value)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("[{items}]")
+        }
+
+        // An empty table must still render as the empty record *literal*
+        // `{=}`; `{ }` is Dhall's empty record *type*, which isn't valid
+        // where a value is expected.
+        ValueKind::Table(table) if table.is_empty() => "{=}".to_string(),
+
+        ValueKind::Table(table) => {
+            let fields = table
+                .iter()
+                .map(|(k, v)| format!("{k} = {}", emit_dhall_value(v)))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("{{ {fields} }}")
+        }
+    }
+}
+
 // Have a proper error fire if the root of a file is ever not a Table
 pub fn extract_root_table(
     uri: Option<&String>,
@@ -42,11 +422,165 @@ pub fn extract_root_table(
         ValueKind::U128(value) => Err(Unexpected::U128(value)),
         ValueKind::Float(value) => Err(Unexpected::Float(value)),
         ValueKind::String(value) => Err(Unexpected::Str(value)),
+        #[cfg(feature = "toml")]
+        ValueKind::DateTime(value) => Err(Unexpected::Str(value.text)),
     }
     .map_err(|err| ConfigError::invalid_root(uri, err))
     .map_err(|err| Box::new(err) as Box<dyn Error + Send + Sync>)
 }
 
+/// Deserializes a built configuration tree into `T`, attaching the full key
+/// path (e.g. `database.pool.max_size`) to any deserialization error instead
+/// of only reporting the mismatched type.
+///
+/// This threads the deserialization through [`serde_path_to_error`], which
+/// wraps the target [`Deserializer`](serde::de::Deserializer) (here, [`Value`])
+/// to push each map key / sequence index it descends through onto a path
+/// stack, then attaches the accumulated path to the error if one occurs.
+pub fn deserialize_with_path<'de, T>(value: Value) -> Result<T, ConfigError>
+where
+    T: Deserialize<'de>,
+{
+    let mut track = serde_path_to_error::Track::new();
+    let deserializer = serde_path_to_error::Deserializer::new(value, &mut track);
+
+    T::deserialize(deserializer).map_err(|err| ConfigError::at_path(track.path().to_string(), err))
+}
+
+/// Deserializes `value` into `T`, additionally returning the dotted path of
+/// every key in the configuration tree that `T`'s `Deserialize` impl never
+/// read — typically because it was misspelled (`databse` instead of
+/// `database`) and so silently fell back to a default with no signal that
+/// anything was wrong.
+///
+/// This uses the [`serde_ignored`] technique: a `Deserializer` wrapper that
+/// records the path of every key skipped over during deserialization.
+pub fn deserialize_collecting_unused<'de, T>(value: Value) -> Result<(T, Vec<String>), ConfigError>
+where
+    T: Deserialize<'de>,
+{
+    let mut unused = Vec::new();
+
+    let result = serde_ignored::deserialize(value, |path| unused.push(path.to_string()))?;
+
+    Ok((result, unused))
+}
+
+/// Like [`deserialize_collecting_unused`], but treats any unused key as a
+/// hard [`ConfigError`] instead of returning them for the caller to log.
+pub fn deserialize_strict<'de, T>(value: Value) -> Result<T, ConfigError>
+where
+    T: Deserialize<'de>,
+{
+    let (result, unused) = deserialize_collecting_unused(value)?;
+
+    if unused.is_empty() {
+        Ok(result)
+    } else {
+        Err(ConfigError::unused_keys(unused))
+    }
+}
+
+/// Supports deserializing a [`Value`] into a Rust enum: a [`ValueKind::String`]
+/// is offered as the variant name for a unit variant, and a single-entry
+/// [`ValueKind::Table`] is offered as `{ <variant> = <body> }`, the externally
+/// tagged shape serde's derived enum `Deserialize` impls expect for a variant
+/// carrying data.
+///
+/// Used by `Value`'s `Deserializer::deserialize_enum` implementation; mirrors
+/// the `deserialize_enum` handling added to the TOML deserializer.
+pub fn deserialize_parsed_enum<'de, V>(value: Value, visitor: V) -> Result<V::Value, ConfigError>
+where
+    V: serde::de::Visitor<'de>,
+{
+    use serde::de::Error as _;
+
+    let origin = value.origin.clone();
+
+    match value.kind {
+        ValueKind::String(variant) => visitor.visit_enum(ParsedEnumAccess {
+            variant,
+            value: Value::new(origin.as_ref(), ValueKind::Nil),
+        }),
+
+        ValueKind::Table(table) if table.len() == 1 => {
+            let (variant, value) = table.into_iter().next().expect("checked len == 1");
+            visitor.visit_enum(ParsedEnumAccess { variant, value })
+        }
+
+        ValueKind::Table(table) => Err(ConfigError::custom(format!(
+            "expected exactly one key identifying the enum variant, found {}",
+            table.len()
+        ))),
+
+        _ => Err(ConfigError::custom(
+            "expected a string (unit variant) or a single-entry table (tagged variant) for an enum value",
+        )),
+    }
+}
+
+struct ParsedEnumAccess {
+    variant: String,
+    value: Value,
+}
+
+impl<'de> serde::de::EnumAccess<'de> for ParsedEnumAccess {
+    type Error = ConfigError;
+    type Variant = ParsedVariantAccess;
+
+    fn variant_seed<S>(self, seed: S) -> Result<(S::Value, Self::Variant), Self::Error>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        use serde::de::IntoDeserializer;
+
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+
+        Ok((variant, ParsedVariantAccess { value: self.value }))
+    }
+}
+
+struct ParsedVariantAccess {
+    value: Value,
+}
+
+impl<'de> serde::de::VariantAccess<'de> for ParsedVariantAccess {
+    type Error = ConfigError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        // Reached via the single-entry table form, `{ <variant> = <body> }`,
+        // for a variant that turns out to carry no data: the body must
+        // actually deserialize as unit rather than being silently discarded,
+        // so a stray value attached to a unit variant is still reported.
+        <() as Deserialize>::deserialize(self.value)
+    }
+
+    fn newtype_variant_seed<S>(self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self.value)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        serde::de::Deserializer::deserialize_seq(self.value, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        serde::de::Deserializer::deserialize_map(self.value, visitor)
+    }
+}
+
 // Equivalent to ValueKind, except Table + Array store the same enum
 // Useful for serde to serialize values into, then convert to Value.
 // NOTE: Order of variants is important. Serde will use whichever
@@ -60,6 +594,9 @@ pub enum ParsedValue {
     U64(u64),
     U128(u128),
     Float(f64),
+    #[cfg(feature = "toml")]
+    #[serde(deserialize_with = "deserialize_parsed_datetime")]
+    DateTime(DateTimeValue),
     #[serde(deserialize_with = "deserialize_parsed_string")]
     String(String),
     #[serde(deserialize_with = "deserialize_parsed_map")]
@@ -76,6 +613,8 @@ pub fn from_parsed_value(uri: Option<&String>, value: ParsedValue) -> Value {
     let vk = match value {
         ParsedValue::Nil => ValueKind::Nil,
         ParsedValue::String(v) => ValueKind::String(v),
+        #[cfg(feature = "toml")]
+        ParsedValue::DateTime(v) => ValueKind::DateTime(v),
         ParsedValue::I64(v) => ValueKind::I64(v),
         ParsedValue::I128(v) => ValueKind::I128(v),
         ParsedValue::U64(v) => ValueKind::U64(v),
@@ -111,6 +650,199 @@ pub fn from_parsed_value(uri: Option<&String>, value: ParsedValue) -> Value {
     Value::new(uri, vk)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(entries: Vec<(&str, Value)>) -> Value {
+        let map = entries.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+
+        Value::new(None, ValueKind::Table(map))
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Inner {
+        max_size: i64,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Outer {
+        database: Inner,
+    }
+
+    #[test]
+    fn deserialize_with_path_reports_the_offending_key_path() {
+        let value = table(vec![(
+            "database",
+            table(vec![(
+                "max_size",
+                Value::new(None, ValueKind::String("not a number".to_string())),
+            )]),
+        )]);
+
+        let err = deserialize_with_path::<Outer>(value).unwrap_err();
+
+        assert!(
+            err.to_string().contains("database.max_size"),
+            "expected the path `database.max_size` in error: {err}"
+        );
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Strict {
+        database: String,
+    }
+
+    #[test]
+    fn deserialize_collecting_unused_reports_keys_nothing_read() {
+        let value = table(vec![
+            (
+                "database",
+                Value::new(None, ValueKind::String("localhost".to_string())),
+            ),
+            (
+                "extra",
+                Value::new(None, ValueKind::String("surprise".to_string())),
+            ),
+        ]);
+
+        let (result, unused): (Strict, Vec<String>) =
+            deserialize_collecting_unused(value).unwrap();
+
+        assert_eq!(result.database, "localhost");
+        assert_eq!(unused, vec!["extra".to_string()]);
+    }
+
+    #[test]
+    fn deserialize_strict_errors_on_unused_keys() {
+        let value = table(vec![
+            (
+                "database",
+                Value::new(None, ValueKind::String("localhost".to_string())),
+            ),
+            (
+                "extra",
+                Value::new(None, ValueKind::String("surprise".to_string())),
+            ),
+        ]);
+
+        let err = deserialize_strict::<Strict>(value).unwrap_err();
+
+        assert!(
+            err.to_string().contains("extra"),
+            "expected the unused key `extra` in error: {err}"
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Mode {
+        Active,
+        Retry { attempts: u32 },
+    }
+
+    struct ModeVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for ModeVisitor {
+        type Value = Mode;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("`active` or a `retry` table")
+        }
+
+        fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::EnumAccess<'de>,
+        {
+            #[derive(serde::Deserialize)]
+            #[serde(rename_all = "lowercase")]
+            enum Field {
+                Active,
+                Retry,
+            }
+
+            use serde::de::VariantAccess;
+
+            let (field, variant) = data.variant()?;
+            match field {
+                Field::Active => {
+                    variant.unit_variant()?;
+                    Ok(Mode::Active)
+                }
+                Field::Retry => {
+                    struct RetryVisitor;
+
+                    impl<'de> serde::de::Visitor<'de> for RetryVisitor {
+                        type Value = Mode;
+
+                        fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                            f.write_str("retry fields")
+                        }
+
+                        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+                        where
+                            A: serde::de::MapAccess<'de>,
+                        {
+                            #[derive(serde::Deserialize)]
+                            struct Body {
+                                attempts: u32,
+                            }
+
+                            let body = Body::deserialize(
+                                serde::de::value::MapAccessDeserializer::new(map),
+                            )?;
+
+                            Ok(Mode::Retry {
+                                attempts: body.attempts,
+                            })
+                        }
+                    }
+
+                    variant.struct_variant(&["attempts"], RetryVisitor)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unit_variant_deserializes_from_a_bare_string() {
+        let value = Value::new(None, ValueKind::String("active".to_string()));
+
+        let mode = deserialize_parsed_enum(value, ModeVisitor).unwrap();
+
+        assert_eq!(mode, Mode::Active);
+    }
+
+    #[test]
+    fn tagged_variant_deserializes_from_a_single_entry_table() {
+        let value = table(vec![(
+            "retry",
+            table(vec![(
+                "attempts",
+                Value::new(None, ValueKind::U64(3)),
+            )]),
+        )]);
+
+        let mode = deserialize_parsed_enum(value, ModeVisitor).unwrap();
+
+        assert_eq!(mode, Mode::Retry { attempts: 3 });
+    }
+
+    #[test]
+    fn tagged_variant_rejects_multi_key_table() {
+        let value = table(vec![
+            ("retry", Value::new(None, ValueKind::Nil)),
+            ("active", Value::new(None, ValueKind::Nil)),
+        ]);
+
+        let err = deserialize_parsed_enum(value, ModeVisitor).unwrap_err();
+
+        assert!(
+            err.to_string().contains('2'),
+            "expected the error to mention the 2 keys found: {err}"
+        );
+    }
+}
+
 fn deserialize_parsed_string<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: serde::de::Deserializer<'de>,
@@ -122,15 +854,138 @@ where
         String(String),
         // Config specific support for types that need string conversion:
         Char(char),
-        #[cfg(feature = "toml")]
-        TomlDateTime(toml::value::Datetime),
     }
 
     match ParsedString::deserialize(deserializer)? {
         ParsedString::String(v) => Ok(v),
         ParsedString::Char(v) => Ok(v.to_string()),
+    }
+}
+
+/// A parsed date/time value, normalized to its canonical RFC 3339 (or
+/// partial-date/partial-time) string form, tagged with which components were
+/// actually present in the source. This is what backs [`ValueKind::DateTime`],
+/// so that e.g. a TOML `1979-05-27` isn't conflated with a user string that
+/// merely looks like a date.
+///
+/// Only ever produced from a format's *native* datetime wire representation
+/// (TOML's own datetime syntax, an explicit YAML `!!timestamp` tag) — never
+/// from reparsing an arbitrary string, so a plain JSON/YAML string that
+/// happens to look like a date stays a [`ValueKind::String`]. This also means
+/// an *implicitly*-typed YAML timestamp with no `!!timestamp` tag (e.g. a
+/// bare `1979-05-27T07:32:00Z`) is indistinguishable from such a string and
+/// is likewise left as [`ValueKind::String`]; see [`YamlTimestamp`] for why.
+#[cfg(feature = "toml")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimeValue {
+    /// Canonical string form, suitable for formats (or `Deserialize` impls)
+    /// that only want the string representation.
+    pub text: String,
+    /// Which of the date/time components are present in `text`.
+    pub tag: DateTimeTag,
+}
+
+/// Which components of a [`DateTimeValue`] are present.
+#[cfg(feature = "toml")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeTag {
+    /// Date only, e.g. `1979-05-27`.
+    Date,
+    /// Time only, e.g. `07:32:00`.
+    Time,
+    /// Full date and time, e.g. `1979-05-27T07:32:00Z`. `has_offset` tells
+    /// apart a UTC/fixed-offset timestamp from a local one with no offset,
+    /// e.g. `1979-05-27T07:32:00` (`has_offset: false`).
+    DateTime { has_offset: bool },
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::value::Datetime> for DateTimeValue {
+    fn from(dt: toml::value::Datetime) -> Self {
+        let tag = match (dt.date.is_some(), dt.time.is_some()) {
+            (true, false) => DateTimeTag::Date,
+            (false, true) => DateTimeTag::Time,
+            // `toml::value::Datetime` always has at least one of date/time set,
+            // and both together is the common full-datetime case:
+            _ => DateTimeTag::DateTime {
+                has_offset: dt.offset.is_some(),
+            },
+        };
+
+        DateTimeValue {
+            text: dt.to_string(),
+            tag,
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+fn deserialize_parsed_datetime<'de, D>(deserializer: D) -> Result<DateTimeValue, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum ParsedDateTime {
+        // The TOML parser hands datetimes to serde via its own private wire
+        // format, which only `toml::value::Datetime`'s `Deserialize` impl
+        // understands — a plain string from any other format's deserializer
+        // can't produce one, so this never misfires on an ordinary string:
+        #[cfg(feature = "toml")]
+        TomlNative(toml::value::Datetime),
+        // Likewise, `YamlTimestamp` only succeeds when the underlying
+        // deserializer actually tagged the scalar as YAML's core
+        // `tag:yaml.org,2002:timestamp` type, not for an arbitrary string:
+        #[cfg(feature = "yaml")]
+        YamlTimestamp(YamlTimestamp),
+    }
+
+    let dt = match ParsedDateTime::deserialize(deserializer)? {
         #[cfg(feature = "toml")]
-        ParsedString::TomlDateTime(v) => Ok(v.to_string()),
+        ParsedDateTime::TomlNative(dt) => dt,
+        #[cfg(feature = "yaml")]
+        ParsedDateTime::YamlTimestamp(YamlTimestamp(dt)) => dt,
+    };
+
+    Ok(DateTimeValue::from(dt))
+}
+
+// Recognizes a YAML scalar explicitly tagged `!!timestamp`, as opposed to an
+// ordinary untagged string that merely looks like a date.
+//
+// Note this only catches the *explicit*-tag form. serde_yaml represents a
+// bare, implicitly-typed timestamp (e.g. `1979-05-27T07:32:00Z` with no
+// `!!timestamp` prefix) as a plain `Value::String`, indistinguishable at this
+// point from a user string that happens to look like a date, so it still
+// falls through to `ValueKind::String` rather than `ValueKind::DateTime`.
+// Recognizing the implicit form too would need its own date/time parsing
+// ahead of the generic `String` fallback below.
+//
+// Reuses `toml::value::Datetime` as the normalized storage, so this is only
+// available when both `toml` and `yaml` are enabled.
+#[cfg(all(feature = "toml", feature = "yaml"))]
+struct YamlTimestamp(toml::value::Datetime);
+
+#[cfg(all(feature = "toml", feature = "yaml"))]
+impl<'de> serde::Deserialize<'de> for YamlTimestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let value = serde_yaml::Value::deserialize(deserializer)?;
+
+        let text = match &value {
+            serde_yaml::Value::Tagged(tagged)
+                if tagged.tag == serde_yaml::value::Tag::new("tag:yaml.org,2002:timestamp") =>
+            {
+                tagged.value.as_str()
+            }
+            _ => None,
+        };
+
+        text.and_then(|text| text.parse().ok())
+            .map(YamlTimestamp)
+            .ok_or_else(|| serde::de::Error::custom("not a YAML timestamp"))
     }
 }
 